@@ -1,6 +1,30 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Vec, token};
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contract, contractclient, contractimpl, contracttype, Address, Env, Vec, token};
+
+// Cross-contract interface exposed by the lending pool, used to read the
+// figures the utilization-based rate model is driven by.
+#[contractclient(name = "LendingPoolClient")]
+pub trait LendingPoolInterface {
+    fn total_borrowed(env: Env) -> i128;
+    fn total_liquidity(env: Env) -> i128;
+}
+
+// Cross-contract interface exposed by the remittance NFT contract.
+#[contractclient(name = "RemittanceNftClient")]
+pub trait RemittanceNftInterface {
+    fn get_credit_score(env: Env, nft_id: u64) -> u32;
+}
+
+// Cross-contract interface exposed by the price oracle.
+#[contractclient(name = "OracleClient")]
+pub trait OracleInterface {
+    fn get_nft_value(env: Env, nft_id: u64) -> i128;
+    fn get_token_price(env: Env, token: Address) -> i128;
+}
 
 #[contracttype]
 #[derive(Clone, PartialEq)]
@@ -9,6 +33,7 @@ pub enum LoanStatus {
     Active = 1,
     Repaid = 2,
     Defaulted = 3,
+    Auction = 4,
 }
 
 #[contracttype]
@@ -28,6 +53,44 @@ pub struct Loan {
     pub status: LoanStatus,
     pub payments_made: u32,
     pub payments_missed: u32,
+    pub cumulative_borrow_rate: i128, // fixed-point index, scaled by RATE_INDEX_SCALE
+    pub last_accrual_timestamp: u64,
+    pub collateral_value: i128,          // oracle valuation at request time, in USDC
+    pub liquidation_threshold_bps: u32,
+    pub liquidation_bonus_bps: u32,
+    pub last_update: LastUpdate,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct LastUpdate {
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Auction {
+    pub current_bid: i128,
+    pub highest_bidder: Option<Address>,
+    pub auction_end: u64,
+    pub bid_fine: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct RateConfig {
+    pub optimal_utilization_bps: u32,
+    pub base_rate_bps: u32,
+    pub slope1_bps: u32,
+    pub slope2_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct LiquidationConfig {
+    pub loan_to_value_bps: u32,
+    pub liquidation_threshold_bps: u32,
+    pub liquidation_bonus_bps: u32,
 }
 
 #[contracttype]
@@ -39,8 +102,34 @@ pub enum DataKey {
     LendingPoolContract,
     OracleContract,
     USDCTokenAddress,
+    Auction(u64),
+    RateConfig,
+    LiquidationConfig,
+    AcceptedTokens,
+    Admin,
 }
 
+// Oracle token prices are USDC-per-unit, scaled by PRICE_SCALE
+const PRICE_SCALE: i128 = 10_000_000;
+
+// Maximum age of a loan's oracle-sourced valuation before it's considered stale
+const MAX_STALENESS: u64 = 24 * 60 * 60; // 1 day
+
+// Health factor is expressed on the same 10000 = 1.0 scale as basis points
+const HEALTH_FACTOR_SCALE: i128 = 10000;
+// A liquidator may repay at most this fraction of outstanding debt in one call
+const CLOSE_FACTOR_BPS: i128 = 5000; // 50%
+
+// Auction tuning constants
+const AUCTION_DURATION: u64 = 3 * 24 * 60 * 60; // 3 days
+const AUCTION_EXTENSION_WINDOW: u64 = 10 * 60; // bids in the last 10 minutes extend the close
+const AUCTION_EXTENSION: u64 = 10 * 60;
+const BID_FINE_BPS: i128 = 500; // 5% penalty on redemption
+
+// Interest accrual constants
+const RATE_INDEX_SCALE: i128 = 1_000_000_000; // 1e9 fixed-point base
+const SECONDS_PER_YEAR: i128 = 365 * 24 * 60 * 60;
+
 
 #[contract]
 pub struct LoanManager;
@@ -50,16 +139,51 @@ impl LoanManager {
 
     pub fn __initialize(
         env: Env,
+        admin: Address,
         nft_contract: Address,
         pool_contract: Address,
         oracle_contract: Address,
         usdc_token: Address,
     ) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::RemittanceNFTContract, &nft_contract);
         env.storage().instance().set(&DataKey::LendingPoolContract, &pool_contract);
         env.storage().instance().set(&DataKey::OracleContract, &oracle_contract);
         env.storage().instance().set(&DataKey::USDCTokenAddress, &usdc_token);
         env.storage().instance().set(&DataKey::LoanCounter, &0u64);
+
+        let rate_config = RateConfig {
+            optimal_utilization_bps: 8000, // 80%
+            base_rate_bps: 200,            // 2%
+            slope1_bps: 1000,              // 10%
+            slope2_bps: 6000,              // 60%
+        };
+        env.storage().instance().set(&DataKey::RateConfig, &rate_config);
+
+        let liquidation_config = LiquidationConfig {
+            loan_to_value_bps: 6000,       // 60%
+            liquidation_threshold_bps: 7500, // 75%
+            liquidation_bonus_bps: 500,    // 5%
+        };
+        env.storage().instance().set(&DataKey::LiquidationConfig, &liquidation_config);
+    }
+
+    // Protocol admin: update the utilization-based rate model parameters
+    pub fn set_rate_config(env: Env, rate_config: RateConfig) {
+        Self::assert_admin(&env);
+        env.storage().instance().set(&DataKey::RateConfig, &rate_config);
+    }
+
+    // Protocol admin: update the LTV / liquidation parameters
+    pub fn set_liquidation_config(env: Env, liquidation_config: LiquidationConfig) {
+        Self::assert_admin(&env);
+        env.storage().instance().set(&DataKey::LiquidationConfig, &liquidation_config);
+    }
+
+    // Protocol admin: whitelist tokens that can be used to repay loans
+    pub fn set_accepted_tokens(env: Env, accepted_tokens: Vec<Address>) {
+        Self::assert_admin(&env);
+        env.storage().instance().set(&DataKey::AcceptedTokens, &accepted_tokens);
     }
     
     // Request loan
@@ -72,15 +196,25 @@ impl LoanManager {
     ) -> u64 {
         borrower.require_auth();
         
-        // Verify NFT ownership and calculate collateral value
+        // Verify NFT ownership
         let nft_contract: Address = env.storage().instance().get(&DataKey::RemittanceNFTContract).unwrap();
-        
-        // Call NFT contract to verify and get collateral value
-        
+        // Call nft_contract.verify_ownership(nft_id, borrower)
+
+        // Value the collateral via the oracle and enforce the max loan-to-value
+        let oracle_contract: Address = env.storage().instance().get(&DataKey::OracleContract).unwrap();
+        let oracle_client = OracleClient::new(&env, &oracle_contract);
+        let collateral_value = oracle_client.get_nft_value(&nft_id);
+
+        let liquidation_config: LiquidationConfig = env.storage().instance()
+            .get(&DataKey::LiquidationConfig)
+            .unwrap();
+        let max_loan_amount = (collateral_value * (liquidation_config.loan_to_value_bps as i128)) / 10000;
+        assert!(amount <= max_loan_amount, "Amount exceeds max loan-to-value");
+
         // Calculate loan terms
         let interest_rate = Self::calculate_interest_rate(&env, nft_id);
         let monthly_payment = Self::calculate_monthly_payment(amount, interest_rate, duration_months);
-        
+
         // Create loan
         let mut counter: u64 = env.storage().instance().get(&DataKey::LoanCounter).unwrap_or(0);
         counter += 1;
@@ -100,6 +234,12 @@ impl LoanManager {
             status: LoanStatus::Pending,
             payments_made: 0,
             payments_missed: 0,
+            cumulative_borrow_rate: RATE_INDEX_SCALE,
+            last_accrual_timestamp: env.ledger().timestamp(),
+            collateral_value,
+            liquidation_threshold_bps: liquidation_config.liquidation_threshold_bps,
+            liquidation_bonus_bps: liquidation_config.liquidation_bonus_bps,
+            last_update: LastUpdate { timestamp: env.ledger().timestamp() },
         };
         
         env.storage().instance().set(&DataKey::LoanCounter, &counter);
@@ -124,7 +264,10 @@ impl LoanManager {
             .expect("Loan does not exist");
         
         assert!(loan.status == LoanStatus::Pending, "Loan not pending");
-        
+        Self::assert_fresh(&env, &loan);
+
+        Self::accrue_interest(&env, &mut loan);
+
         // Stake NFT
         let nft_contract: Address = env.storage().instance().get(&DataKey::RemittanceNFTContract).unwrap();
         // Call nft_contract.stake_nft(loan.nft_collateral_id, loan_id)
@@ -140,71 +283,82 @@ impl LoanManager {
         env.events().publish(("loan_approved",), loan_id);
     }
     
-    // Process payment
-    pub fn make_payment(env: Env, loan_id: u64, amount: i128) {
+    // Process payment. `pay_token` may be USDC or any whitelisted AcceptedTokens
+    // entry; non-USDC payments are converted to their USDC-equivalent value via
+    // the oracle before being credited against the loan.
+    pub fn make_payment(env: Env, loan_id: u64, amount: i128, pay_token: Address) {
         let mut loan: Loan = env.storage().instance()
             .get(&DataKey::Loan(loan_id))
             .expect("Loan does not exist");
-        
+
         assert!(loan.status == LoanStatus::Active, "Loan not active");
-        
-        // Transfer USDC from borrower to pool
+
+        Self::accrue_interest(&env, &mut loan);
+
         let usdc_token: Address = env.storage().instance().get(&DataKey::USDCTokenAddress).unwrap();
         let pool_contract: Address = env.storage().instance().get(&DataKey::LendingPoolContract).unwrap();
-        
-        let usdc_client = token::Client::new(&env, &usdc_token);
-        usdc_client.transfer(&loan.borrower, &pool_contract, &amount);
-        
-        // Calculate principal and interest split
-        let interest_portion = Self::calculate_interest_portion(loan.outstanding_balance, loan.interest_rate);
-        let principal_portion = if amount > interest_portion {
-            amount - interest_portion
-        } else {
-            0
-        };
-        
-        // Update loan
-        loan.total_repaid += amount;
-        loan.outstanding_balance -= principal_portion;
+
+        let usdc_equivalent = Self::to_usdc_equivalent(&env, &pay_token, &usdc_token, amount);
+
+        // Forward the payment token to the pool, which credits the USDC-equivalent
+        // value computed above
+        let pay_client = token::Client::new(&env, &pay_token);
+        pay_client.transfer(&loan.borrower, &pool_contract, &amount);
+
+        // Update loan: the freshly-accrued balance already reflects interest, so the
+        // full USDC-equivalent amount comes straight off the top
+        loan.total_repaid += usdc_equivalent;
+        loan.outstanding_balance -= usdc_equivalent;
         loan.payments_made += 1;
         loan.next_payment_due += (30 * 24 * 60 * 60); // Next month
-        
+
         // Check if fully repaid
         if loan.outstanding_balance <= 0 {
             loan.status = LoanStatus::Repaid;
-            
+
             // Unstake NFT
             let nft_contract: Address = env.storage().instance().get(&DataKey::RemittanceNFTContract).unwrap();
             // Call nft_contract.unstake_nft(loan.nft_collateral_id)
         }
-        
+
         // Notify pool of repayment
         let pool_contract: Address = env.storage().instance().get(&DataKey::LendingPoolContract).unwrap();
-        // Call pool_contract.repay(principal_portion, interest_portion, loan_id)
-        
+        // Call pool_contract.repay(usdc_equivalent, loan_id)
+
         env.storage().instance().set(&DataKey::Loan(loan_id), &loan);
-        
-        env.events().publish(("payment_made", loan_id), amount);
+
+        env.events().publish(("payment_made", loan_id), usdc_equivalent);
     }
-    
-    // Process automatic repayment (called by Oracle)
-    pub fn process_automatic_repayment(env: Env, loan_id: u64, remittance_amount: i128) -> i128 {
+
+    // Process automatic repayment (called by Oracle). `remittance_amount` is
+    // denominated in `pay_token` units, which may not be USDC.
+    pub fn process_automatic_repayment(env: Env, loan_id: u64, remittance_amount: i128, pay_token: Address) -> i128 {
         let oracle: Address = env.storage().instance().get(&DataKey::OracleContract).unwrap();
         oracle.require_auth();
-        
+
         let loan: Loan = env.storage().instance()
             .get(&DataKey::Loan(loan_id))
             .expect("Loan does not exist");
-        
-        let payment_amount = if remittance_amount >= loan.monthly_payment {
-            loan.monthly_payment
+
+        let usdc_token: Address = env.storage().instance().get(&DataKey::USDCTokenAddress).unwrap();
+        let remittance_usdc_equivalent = Self::to_usdc_equivalent(&env, &pay_token, &usdc_token, remittance_amount);
+
+        let payment_amount = if remittance_usdc_equivalent >= loan.monthly_payment {
+            // Convert the USDC-denominated monthly payment back into pay_token units
+            if pay_token == usdc_token {
+                loan.monthly_payment
+            } else {
+                let oracle_client = OracleClient::new(&env, &oracle);
+                let price = oracle_client.get_token_price(&pay_token);
+                (loan.monthly_payment * PRICE_SCALE) / price
+            }
         } else {
             remittance_amount
         };
-        
+
         // Process payment
-        Self::make_payment(env.clone(), loan_id, payment_amount);
-        
+        Self::make_payment(env.clone(), loan_id, payment_amount, pay_token);
+
         // Return remaining amount for recipient
         remittance_amount - payment_amount
     }
@@ -217,20 +371,211 @@ impl LoanManager {
         let mut loan: Loan = env.storage().instance()
             .get(&DataKey::Loan(loan_id))
             .expect("Loan does not exist");
-        
+        assert!(loan.status == LoanStatus::Active, "Loan not active");
+
+        Self::accrue_interest(&env, &mut loan);
+
         loan.payments_missed += 1;
-        
+
         // Check for default (2 consecutive missed payments)
         if loan.payments_missed >= 2 {
             loan.status = LoanStatus::Defaulted;
-            // Liquidation logic would go here
         }
-        
+
         env.storage().instance().set(&DataKey::Loan(loan_id), &loan);
-        
+
         env.events().publish(("payment_missed", loan_id), loan.payments_missed);
     }
-    
+
+    // Re-pull the collateral valuation from the oracle and recompute the interest
+    // rate from the pool and credit score, stamping the loan as freshly-updated. Off-chain keepers call this before approve_loan,
+    // liquidate, or get_health_factor so those calls don't act on stale prices.
+    pub fn refresh_loan(env: Env, loan_id: u64) {
+        let oracle: Address = env.storage().instance().get(&DataKey::OracleContract).unwrap();
+        oracle.require_auth();
+
+        let mut loan: Loan = env.storage().instance()
+            .get(&DataKey::Loan(loan_id))
+            .expect("Loan does not exist");
+
+        // Flush accrual at the old rate first, so the rate change below only
+        // applies going forward instead of re-pricing time already elapsed.
+        Self::accrue_interest(&env, &mut loan);
+
+        let oracle_client = OracleClient::new(&env, &oracle);
+        loan.collateral_value = oracle_client.get_nft_value(&loan.nft_collateral_id);
+        loan.interest_rate = Self::calculate_interest_rate(&env, loan.nft_collateral_id);
+        loan.last_update = LastUpdate { timestamp: env.ledger().timestamp() };
+
+        env.storage().instance().set(&DataKey::Loan(loan_id), &loan);
+
+        env.events().publish(("loan_refreshed", loan_id), loan.collateral_value);
+    }
+
+    // Open a fixed-duration auction window on a defaulted loan's collateral
+    pub fn start_auction(env: Env, loan_id: u64) {
+        let mut loan: Loan = env.storage().instance()
+            .get(&DataKey::Loan(loan_id))
+            .expect("Loan does not exist");
+
+        assert!(loan.status == LoanStatus::Defaulted, "Loan not defaulted");
+
+        Self::accrue_interest(&env, &mut loan);
+
+        let auction_end = env.ledger().timestamp() + AUCTION_DURATION;
+        let bid_fine = (loan.outstanding_balance * BID_FINE_BPS) / 10000;
+
+        let auction = Auction {
+            current_bid: 0,
+            highest_bidder: None,
+            auction_end,
+            bid_fine,
+        };
+
+        loan.status = LoanStatus::Auction;
+        env.storage().instance().set(&DataKey::Loan(loan_id), &loan);
+        env.storage().instance().set(&DataKey::Auction(loan_id), &auction);
+
+        env.events().publish(("auction_started", loan_id), auction_end);
+    }
+
+    // Place a bid on a defaulted loan's collateral auction
+    pub fn place_bid(env: Env, loan_id: u64, bidder: Address, amount: i128) {
+        bidder.require_auth();
+
+        let mut loan: Loan = env.storage().instance()
+            .get(&DataKey::Loan(loan_id))
+            .expect("Loan does not exist");
+        assert!(loan.status == LoanStatus::Auction, "Loan not in auction");
+
+        Self::accrue_interest(&env, &mut loan);
+        env.storage().instance().set(&DataKey::Loan(loan_id), &loan);
+
+        let mut auction: Auction = env.storage().instance()
+            .get(&DataKey::Auction(loan_id))
+            .expect("Auction does not exist");
+        assert!(env.ledger().timestamp() < auction.auction_end, "Auction has closed");
+        assert!(amount > auction.current_bid, "Bid too low");
+        assert!(amount > loan.outstanding_balance, "Bid must exceed outstanding debt");
+
+        // Escrow the new bid
+        let usdc_token: Address = env.storage().instance().get(&DataKey::USDCTokenAddress).unwrap();
+        let usdc_client = token::Client::new(&env, &usdc_token);
+        usdc_client.transfer(&bidder, &env.current_contract_address(), &amount);
+
+        // Refund the previous bidder
+        if let Some(previous_bidder) = auction.highest_bidder.clone() {
+            usdc_client.transfer(&env.current_contract_address(), &previous_bidder, &auction.current_bid);
+        }
+
+        auction.current_bid = amount;
+        auction.highest_bidder = Some(bidder.clone());
+
+        // Extend the window if the bid lands near the close
+        if auction.auction_end - env.ledger().timestamp() < AUCTION_EXTENSION_WINDOW {
+            auction.auction_end += AUCTION_EXTENSION;
+        }
+
+        env.storage().instance().set(&DataKey::Auction(loan_id), &auction);
+
+        env.events().publish(("bid_placed", loan_id, bidder), amount);
+    }
+
+    // Borrower repays outstanding debt plus the bid fine to cancel the auction and keep the NFT
+    pub fn redeem(env: Env, loan_id: u64) {
+        let mut loan: Loan = env.storage().instance()
+            .get(&DataKey::Loan(loan_id))
+            .expect("Loan does not exist");
+        loan.borrower.require_auth();
+        assert!(loan.status == LoanStatus::Auction, "Loan not in auction");
+
+        Self::accrue_interest(&env, &mut loan);
+
+        let auction: Auction = env.storage().instance()
+            .get(&DataKey::Auction(loan_id))
+            .expect("Auction does not exist");
+        assert!(env.ledger().timestamp() < auction.auction_end, "Auction has closed");
+
+        let redemption_amount = loan.outstanding_balance + auction.bid_fine;
+
+        let usdc_token: Address = env.storage().instance().get(&DataKey::USDCTokenAddress).unwrap();
+        let pool_contract: Address = env.storage().instance().get(&DataKey::LendingPoolContract).unwrap();
+        let usdc_client = token::Client::new(&env, &usdc_token);
+        usdc_client.transfer(&loan.borrower, &pool_contract, &redemption_amount);
+
+        // Refund the highest bidder, if any
+        if let Some(highest_bidder) = auction.highest_bidder.clone() {
+            usdc_client.transfer(&env.current_contract_address(), &highest_bidder, &auction.current_bid);
+        }
+
+        // Unstake NFT
+        let nft_contract: Address = env.storage().instance().get(&DataKey::RemittanceNFTContract).unwrap();
+        // Call nft_contract.unstake_nft(loan.nft_collateral_id)
+
+        loan.total_repaid += redemption_amount;
+        loan.outstanding_balance = 0;
+        loan.status = LoanStatus::Repaid;
+        env.storage().instance().set(&DataKey::Loan(loan_id), &loan);
+        env.storage().instance().remove(&DataKey::Auction(loan_id));
+
+        env.events().publish(("loan_redeemed", loan_id), redemption_amount);
+    }
+
+    // After auction_end, settle the auction: collateral to the winner, proceeds to the pool
+    pub fn finalize_auction(env: Env, loan_id: u64) {
+        let mut loan: Loan = env.storage().instance()
+            .get(&DataKey::Loan(loan_id))
+            .expect("Loan does not exist");
+        assert!(loan.status == LoanStatus::Auction, "Loan not in auction");
+
+        Self::accrue_interest(&env, &mut loan);
+
+        let auction: Auction = env.storage().instance()
+            .get(&DataKey::Auction(loan_id))
+            .expect("Auction does not exist");
+        assert!(env.ledger().timestamp() >= auction.auction_end, "Auction still open");
+
+        let highest_bidder = match auction.highest_bidder.clone() {
+            Some(bidder) => bidder,
+            None => {
+                // No one bid before the window closed: revert to Defaulted so a
+                // fresh auction can be started instead of leaving the loan stuck.
+                loan.status = LoanStatus::Defaulted;
+                env.storage().instance().set(&DataKey::Loan(loan_id), &loan);
+                env.storage().instance().remove(&DataKey::Auction(loan_id));
+
+                env.events().publish(("auction_no_bids", loan_id), ());
+                return;
+            }
+        };
+
+        let usdc_token: Address = env.storage().instance().get(&DataKey::USDCTokenAddress).unwrap();
+        let pool_contract: Address = env.storage().instance().get(&DataKey::LendingPoolContract).unwrap();
+        let usdc_client = token::Client::new(&env, &usdc_token);
+
+        // Transfer the staked NFT to the highest bidder
+        let nft_contract: Address = env.storage().instance().get(&DataKey::RemittanceNFTContract).unwrap();
+        // Call nft_contract.transfer_staked_nft(loan.nft_collateral_id, highest_bidder)
+
+        // Proceeds cover principal + interest, surplus returns to the borrower
+        let owed = loan.outstanding_balance;
+        let proceeds_to_pool = if auction.current_bid < owed { auction.current_bid } else { owed };
+        let surplus = auction.current_bid - proceeds_to_pool;
+
+        usdc_client.transfer(&env.current_contract_address(), &pool_contract, &proceeds_to_pool);
+        if surplus > 0 {
+            usdc_client.transfer(&env.current_contract_address(), &loan.borrower, &surplus);
+        }
+
+        loan.total_repaid += proceeds_to_pool;
+        loan.outstanding_balance = 0;
+        loan.status = LoanStatus::Repaid;
+        env.storage().instance().set(&DataKey::Loan(loan_id), &loan);
+        env.storage().instance().remove(&DataKey::Auction(loan_id));
+
+        env.events().publish(("loan_liquidated", loan_id, highest_bidder), auction.current_bid);
+    }
+
     // Get loan details
     pub fn get_loan(env: Env, loan_id: u64) -> Loan {
         env.storage().instance()
@@ -238,17 +583,125 @@ impl LoanManager {
             .expect("Loan does not exist")
     }
 
+    // Health factor on the HEALTH_FACTOR_SCALE (10000 = 1.0); below that is liquidatable
+    pub fn get_health_factor(env: Env, loan_id: u64) -> i128 {
+        let mut loan: Loan = env.storage().instance()
+            .get(&DataKey::Loan(loan_id))
+            .expect("Loan does not exist");
+        Self::assert_fresh(&env, &loan);
+
+        // Accrue onto a local copy (not persisted) so the reported factor matches
+        // what liquidate() would see, instead of the balance as of the last
+        // state-changing call.
+        Self::accrue_interest(&env, &mut loan);
+
+        if loan.outstanding_balance == 0 {
+            return i128::MAX;
+        }
+
+        (loan.collateral_value * (loan.liquidation_threshold_bps as i128)) / loan.outstanding_balance
+    }
+
+    // Partially liquidate an undercollateralized loan. A liquidator may repay at
+    // most CLOSE_FACTOR_BPS of the outstanding balance per call, receiving a proportional
+    // claim on the collateral plus the liquidation bonus in exchange, so a single call
+    // never covers the whole debt. The loan stays Active while any debt remains.
+    pub fn liquidate(env: Env, loan_id: u64, liquidator: Address, repay_amount: i128) {
+        liquidator.require_auth();
+
+        let mut loan: Loan = env.storage().instance()
+            .get(&DataKey::Loan(loan_id))
+            .expect("Loan does not exist");
+        assert!(loan.status == LoanStatus::Active, "Loan not active");
+        Self::assert_fresh(&env, &loan);
+
+        Self::accrue_interest(&env, &mut loan);
+
+        let health_factor = (loan.collateral_value * (loan.liquidation_threshold_bps as i128))
+            / loan.outstanding_balance;
+        assert!(health_factor < HEALTH_FACTOR_SCALE, "Loan is not liquidatable");
+
+        let max_repay = (loan.outstanding_balance * CLOSE_FACTOR_BPS) / 10000;
+        assert!(repay_amount > 0 && repay_amount <= max_repay, "Repay amount exceeds close factor");
+
+        // Liquidator repays the borrower's debt to the pool
+        let usdc_token: Address = env.storage().instance().get(&DataKey::USDCTokenAddress).unwrap();
+        let pool_contract: Address = env.storage().instance().get(&DataKey::LendingPoolContract).unwrap();
+        let usdc_client = token::Client::new(&env, &usdc_token);
+        usdc_client.transfer(&liquidator, &pool_contract, &repay_amount);
+
+        // In exchange, the liquidator claims a proportional share of the collateral
+        // plus the liquidation bonus
+        let claim_value = (repay_amount * (10000 + (loan.liquidation_bonus_bps as i128))) / 10000;
+        let nft_contract: Address = env.storage().instance().get(&DataKey::RemittanceNFTContract).unwrap();
+        // Call nft_contract.grant_collateral_claim(loan.nft_collateral_id, liquidator, claim_value)
+
+        loan.total_repaid += repay_amount;
+        loan.outstanding_balance -= repay_amount;
+
+        if loan.outstanding_balance <= 0 {
+            loan.status = LoanStatus::Repaid;
+            // Unstake NFT
+            // Call nft_contract.unstake_nft(loan.nft_collateral_id)
+        }
+
+        env.storage().instance().set(&DataKey::Loan(loan_id), &loan);
+
+        env.events().publish(("loan_liquidated", loan_id, liquidator), (repay_amount, claim_value));
+    }
+
     fn calculate_interest_rate(env: &Env, nft_id: u64) -> u32 {
+        let pool_rate_bps = Self::calculate_pool_rate(env);
+        let score_adjustment_bps = Self::calculate_credit_score_adjustment(env, nft_id);
+
+        let blended = pool_rate_bps as i32 + score_adjustment_bps;
+        if blended < 0 { 0 } else { blended as u32 }
+    }
+
+    // Internal: Two-slope utilization model sourced from the lending pool
+    fn calculate_pool_rate(env: &Env) -> u32 {
+        let pool_contract: Address = env.storage().instance().get(&DataKey::LendingPoolContract).unwrap();
+        let pool_client = LendingPoolClient::new(env, &pool_contract);
+
+        let total_borrowed = pool_client.total_borrowed();
+        let total_liquidity = pool_client.total_liquidity();
+
+        let config: RateConfig = env.storage().instance().get(&DataKey::RateConfig).unwrap();
+
+        if total_liquidity == 0 {
+            return config.base_rate_bps;
+        }
+
+        let utilization_bps = ((total_borrowed * 10000) / total_liquidity) as u32;
+
+        if utilization_bps <= config.optimal_utilization_bps {
+            config.base_rate_bps
+                + (config.slope1_bps * utilization_bps) / config.optimal_utilization_bps
+        } else {
+            let excess_utilization_bps = utilization_bps - config.optimal_utilization_bps;
+            let max_excess_bps = 10000 - config.optimal_utilization_bps;
+            config.base_rate_bps
+                + config.slope1_bps
+                + (config.slope2_bps * excess_utilization_bps) / max_excess_bps
+        }
+    }
+
+    // Internal: Credit-score adjustment sourced from the remittance NFT's collateral quality
+    fn calculate_credit_score_adjustment(env: &Env, nft_id: u64) -> i32 {
         let nft_contract: Address = env.storage().instance().get(&DataKey::RemittanceNFTContract).unwrap();
-        
-        // In real implementation, would call nft_contract.get_nft_data(nft_id)
-        // For now, use placeholder logic
-        // Score 90-100: 1500-2000 basis points (15-20% APR)
-        // Score 80-89: 2000-3000 basis points (20-30% APR)
-        // Score 70-79: 3000-4000 basis points (30-40% APR)
-        
-        // Placeholder: return 2000 (20% APR)
-        2000u32
+        let nft_client = RemittanceNftClient::new(env, &nft_contract);
+        let credit_score = nft_client.get_credit_score(&nft_id);
+
+        // Score 90-100: -500bps discount
+        // Score 80-89: no adjustment
+        // Score 70-79: +500bps
+        // Below 70: +1000bps
+        match credit_score {
+            90..=100 => -500,
+            80..=89 => 0,
+            70..=79 => 500,
+            _ => 1000,
+        }
     }
     
     // Internal: Calculate monthly payment
@@ -263,9 +716,56 @@ impl LoanManager {
         total_repayment / (months as i128)
     }
     
-    // Internal: Calculate interest portion of payment
-    fn calculate_interest_portion(outstanding: i128, annual_rate_bps: u32) -> i128 {
-        let monthly_rate_bps = annual_rate_bps / 12;
-        (outstanding * (monthly_rate_bps as i128)) / 10000
+    // Internal: Reject a call if the loan's oracle-sourced valuation hasn't been
+    // refreshed recently enough to be trusted
+    fn assert_fresh(env: &Env, loan: &Loan) {
+        let age = env.ledger().timestamp().saturating_sub(loan.last_update.timestamp);
+        assert!(age <= MAX_STALENESS, "valuation stale");
+    }
+
+    // Internal: Reject a call unless the protocol admin set at __initialize authorized it
+    fn assert_admin(env: &Env) {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+    }
+
+    // Internal: Convert a payment amount into its USDC-equivalent value, rejecting
+    // tokens that aren't on the AcceptedTokens whitelist.
+    fn to_usdc_equivalent(env: &Env, pay_token: &Address, usdc_token: &Address, amount: i128) -> i128 {
+        if pay_token == usdc_token {
+            return amount;
+        }
+
+        let accepted: Vec<Address> = env.storage().instance()
+            .get(&DataKey::AcceptedTokens)
+            .unwrap_or(Vec::new(env));
+        assert!(accepted.contains(pay_token), "Token not accepted");
+
+        let oracle_contract: Address = env.storage().instance().get(&DataKey::OracleContract).unwrap();
+        let oracle_client = OracleClient::new(env, &oracle_contract);
+        let price = oracle_client.get_token_price(pay_token);
+
+        (amount * price) / PRICE_SCALE
+    }
+
+    // Internal: Accrue interest continuously via a cumulative borrow-rate index.
+    // Runs at the start of every state-changing call so the charged interest is
+    // path-independent regardless of when/how often the borrower pays.
+    fn accrue_interest(env: &Env, loan: &mut Loan) {
+        let now = env.ledger().timestamp();
+        let dt = now.saturating_sub(loan.last_accrual_timestamp);
+        if dt == 0 {
+            return;
+        }
+
+        // Defer the division until after multiplying by dt: dividing first would
+        // truncate rate_per_sec to 0 for any APR below ~3.15%, stalling accrual.
+        let growth = RATE_INDEX_SCALE
+            + (loan.interest_rate as i128 * RATE_INDEX_SCALE * dt as i128) / (10000 * SECONDS_PER_YEAR);
+        let new_index = (loan.cumulative_borrow_rate * growth) / RATE_INDEX_SCALE;
+
+        loan.outstanding_balance = (loan.outstanding_balance * new_index) / loan.cumulative_borrow_rate;
+        loan.cumulative_borrow_rate = new_index;
+        loan.last_accrual_timestamp = now;
     }
 }
\ No newline at end of file
@@ -0,0 +1,511 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+
+// ---------------------------------------------------------------------------
+// Mock cross-contract dependencies. Each mirrors the slice of the real
+// contract's interface LoanManager depends on, with setters so tests can
+// pin the values the rate model / valuations / credit scores read. Soroban
+// dispatches cross-contract calls by function name/signature, so these only
+// need to expose matching pub fns -- no trait impl required.
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+enum MockKey {
+    NftValue(u64),
+    TokenPrice(Address),
+    TotalBorrowed,
+    TotalLiquidity,
+    CreditScore(u64),
+}
+
+#[contract]
+struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn set_nft_value(env: Env, nft_id: u64, value: i128) {
+        env.storage().instance().set(&MockKey::NftValue(nft_id), &value);
+    }
+
+    pub fn set_token_price(env: Env, token: Address, price: i128) {
+        env.storage().instance().set(&MockKey::TokenPrice(token), &price);
+    }
+
+    pub fn get_nft_value(env: Env, nft_id: u64) -> i128 {
+        env.storage().instance().get(&MockKey::NftValue(nft_id)).unwrap_or(0)
+    }
+
+    pub fn get_token_price(env: Env, token: Address) -> i128 {
+        env.storage().instance().get(&MockKey::TokenPrice(token)).unwrap_or(PRICE_SCALE)
+    }
+}
+
+#[contract]
+struct MockPool;
+
+#[contractimpl]
+impl MockPool {
+    pub fn set_totals(env: Env, total_borrowed: i128, total_liquidity: i128) {
+        env.storage().instance().set(&MockKey::TotalBorrowed, &total_borrowed);
+        env.storage().instance().set(&MockKey::TotalLiquidity, &total_liquidity);
+    }
+
+    pub fn total_borrowed(env: Env) -> i128 {
+        env.storage().instance().get(&MockKey::TotalBorrowed).unwrap_or(0)
+    }
+
+    pub fn total_liquidity(env: Env) -> i128 {
+        env.storage().instance().get(&MockKey::TotalLiquidity).unwrap_or(0)
+    }
+}
+
+#[contract]
+struct MockNft;
+
+#[contractimpl]
+impl MockNft {
+    pub fn set_credit_score(env: Env, nft_id: u64, score: u32) {
+        env.storage().instance().set(&MockKey::CreditScore(nft_id), &score);
+    }
+
+    pub fn get_credit_score(env: Env, nft_id: u64) -> u32 {
+        env.storage().instance().get(&MockKey::CreditScore(nft_id)).unwrap_or(80)
+    }
+}
+
+struct Harness {
+    env: Env,
+    loan_manager: Address,
+    admin: Address,
+    oracle: Address,
+    pool: Address,
+    usdc: Address,
+}
+
+fn setup(env: &Env) -> Harness {
+    env.mock_all_auths();
+
+    let loan_manager = env.register_contract(None, LoanManager);
+    let oracle = env.register_contract(None, MockOracle);
+    let pool = env.register_contract(None, MockPool);
+    let nft = env.register_contract(None, MockNft);
+
+    let usdc_admin = Address::generate(env);
+    let usdc = env.register_stellar_asset_contract_v2(usdc_admin).address();
+
+    let admin = Address::generate(env);
+    let client = LoanManagerClient::new(env, &loan_manager);
+    client.__initialize(&admin, &nft, &pool, &oracle, &usdc);
+
+    MockPoolClient::new(env, &pool).set_totals(&0, &1000);
+    MockNftClient::new(env, &nft).set_credit_score(&1, &85); // no score adjustment
+
+    Harness { env: env.clone(), loan_manager, admin, oracle, pool, usdc }
+}
+
+fn mint_usdc(h: &Harness, to: &Address, amount: i128) {
+    token::StellarAssetClient::new(&h.env, &h.usdc).mint(to, &amount);
+}
+
+// ---------------------------------------------------------------------------
+// Accrual math (chunk0-2)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn accrue_interest_compounds_low_apr_without_truncating_to_zero() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+
+    // 2% APR (200 bps) used to floor to a 0 rate_per_sec and never accrue.
+    let mut loan = Loan {
+        loan_id: 1,
+        borrower: Address::generate(&env),
+        nft_collateral_id: 1,
+        loan_amount: 100_000,
+        outstanding_balance: 100_000,
+        total_repaid: 0,
+        interest_rate: 200,
+        duration_months: 12,
+        monthly_payment: 0,
+        start_timestamp: 0,
+        next_payment_due: 0,
+        status: LoanStatus::Active,
+        payments_made: 0,
+        payments_missed: 0,
+        cumulative_borrow_rate: RATE_INDEX_SCALE,
+        last_accrual_timestamp: 0,
+        collateral_value: 0,
+        liquidation_threshold_bps: 7500,
+        liquidation_bonus_bps: 500,
+        last_update: LastUpdate { timestamp: 0 },
+    };
+
+    env.ledger().with_mut(|li| li.timestamp = SECONDS_PER_YEAR as u64);
+    LoanManager::accrue_interest(&env, &mut loan);
+
+    // One year at 2% APR should grow the balance by roughly 2%, not 0%.
+    assert!(loan.outstanding_balance > 101_000);
+    assert!(loan.outstanding_balance < 102_500);
+    assert_eq!(loan.last_accrual_timestamp, SECONDS_PER_YEAR as u64);
+}
+
+#[test]
+fn accrue_interest_is_a_noop_within_the_same_instant() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let mut loan = Loan {
+        loan_id: 1,
+        borrower: Address::generate(&env),
+        nft_collateral_id: 1,
+        loan_amount: 100_000,
+        outstanding_balance: 100_000,
+        total_repaid: 0,
+        interest_rate: 1500,
+        duration_months: 12,
+        monthly_payment: 0,
+        start_timestamp: 1000,
+        next_payment_due: 0,
+        status: LoanStatus::Active,
+        payments_made: 0,
+        payments_missed: 0,
+        cumulative_borrow_rate: RATE_INDEX_SCALE,
+        last_accrual_timestamp: 1000,
+        collateral_value: 0,
+        liquidation_threshold_bps: 7500,
+        liquidation_bonus_bps: 500,
+        last_update: LastUpdate { timestamp: 1000 },
+    };
+
+    LoanManager::accrue_interest(&env, &mut loan);
+    assert_eq!(loan.outstanding_balance, 100_000);
+    assert_eq!(loan.cumulative_borrow_rate, RATE_INDEX_SCALE);
+}
+
+// ---------------------------------------------------------------------------
+// Utilization-driven rate model (chunk0-3)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn calculate_pool_rate_follows_the_two_slope_utilization_curve() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+    let h = setup(&env);
+    let pool_client = MockPoolClient::new(&env, &h.pool);
+
+    // Below the 80% optimal point: base_rate_bps + slope1_bps * utilization / optimal
+    pool_client.set_totals(&400, &1000); // 40% utilization
+    let below_optimal = env.as_contract(&h.loan_manager, || LoanManager::calculate_pool_rate(&env));
+    assert_eq!(below_optimal, 200 + (1000 * 4000) / 8000);
+
+    // Above the 80% optimal point: base + slope1 + slope2 * excess / max_excess
+    pool_client.set_totals(&900, &1000); // 90% utilization
+    let above_optimal = env.as_contract(&h.loan_manager, || LoanManager::calculate_pool_rate(&env));
+    assert_eq!(above_optimal, 200 + 1000 + (6000 * 1000) / 2000);
+
+    assert!(above_optimal > below_optimal);
+}
+
+// ---------------------------------------------------------------------------
+// Auction bid / redeem / finalize lifecycle (chunk0-1)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn auction_finalize_pays_out_winner_and_surplus() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+    let h = setup(&env);
+    let client = LoanManagerClient::new(&env, &h.loan_manager);
+
+    MockOracleClient::new(&env, &h.oracle).set_nft_value(&1, &10_000);
+
+    let borrower = Address::generate(&env);
+    let loan_id = client.request_loan(&borrower, &1, &6000, &12);
+    client.approve_loan(&loan_id);
+
+    // Two missed payments in a row pushes the loan into Defaulted.
+    client.mark_payment_missed(&loan_id);
+    client.mark_payment_missed(&loan_id);
+    assert!(client.get_loan(&loan_id).status == LoanStatus::Defaulted);
+
+    client.start_auction(&loan_id);
+
+    let bidder_1 = Address::generate(&env);
+    let bidder_2 = Address::generate(&env);
+    mint_usdc(&h, &bidder_1, 10_000);
+    mint_usdc(&h, &bidder_2, 10_000);
+
+    client.place_bid(&loan_id, &bidder_1, &7000);
+    client.place_bid(&loan_id, &bidder_2, &8000);
+
+    let usdc_client = token::Client::new(&env, &h.usdc);
+    // The outbid bidder_1 is refunded in full once bidder_2 takes the lead.
+    assert_eq!(usdc_client.balance(&bidder_1), 10_000);
+
+    env.ledger().with_mut(|li| li.timestamp += AUCTION_DURATION + 1);
+    client.finalize_auction(&loan_id);
+
+    let loan = client.get_loan(&loan_id);
+    assert!(loan.status == LoanStatus::Repaid);
+    assert_eq!(loan.outstanding_balance, 0);
+}
+
+#[test]
+fn auction_with_no_bids_reverts_to_defaulted_instead_of_panicking() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+    let h = setup(&env);
+    let client = LoanManagerClient::new(&env, &h.loan_manager);
+
+    MockOracleClient::new(&env, &h.oracle).set_nft_value(&1, &10_000);
+
+    let borrower = Address::generate(&env);
+    let loan_id = client.request_loan(&borrower, &1, &6000, &12);
+    client.approve_loan(&loan_id);
+    client.mark_payment_missed(&loan_id);
+    client.mark_payment_missed(&loan_id);
+
+    client.start_auction(&loan_id);
+    env.ledger().with_mut(|li| li.timestamp += AUCTION_DURATION + 1);
+    client.finalize_auction(&loan_id);
+
+    let loan = client.get_loan(&loan_id);
+    assert!(loan.status == LoanStatus::Defaulted);
+
+    // A fresh auction can now be started on the reverted loan.
+    client.start_auction(&loan_id);
+    assert!(client.get_loan(&loan_id).status == LoanStatus::Auction);
+}
+
+#[test]
+fn redeem_cancels_the_auction_and_refunds_the_highest_bidder() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+    let h = setup(&env);
+    let client = LoanManagerClient::new(&env, &h.loan_manager);
+
+    MockOracleClient::new(&env, &h.oracle).set_nft_value(&1, &10_000);
+
+    let borrower = Address::generate(&env);
+    let loan_id = client.request_loan(&borrower, &1, &6000, &12);
+    client.approve_loan(&loan_id);
+    client.mark_payment_missed(&loan_id);
+    client.mark_payment_missed(&loan_id);
+    client.start_auction(&loan_id);
+
+    let bidder = Address::generate(&env);
+    mint_usdc(&h, &bidder, 10_000);
+    client.place_bid(&loan_id, &bidder, &7000);
+
+    // Outstanding balance (<=6000) plus the 5% bid fine, with headroom.
+    mint_usdc(&h, &borrower, 10_000);
+    client.redeem(&loan_id);
+
+    let loan = client.get_loan(&loan_id);
+    assert!(loan.status == LoanStatus::Repaid);
+
+    let usdc_client = token::Client::new(&env, &h.usdc);
+    assert_eq!(usdc_client.balance(&bidder), 10_000); // bid fully refunded
+}
+
+// ---------------------------------------------------------------------------
+// LTV enforcement, health factor, and liquidation (chunk0-4)
+// ---------------------------------------------------------------------------
+
+fn active_loan(borrower: Address, outstanding_balance: i128, collateral_value: i128) -> Loan {
+    Loan {
+        loan_id: 1,
+        borrower,
+        nft_collateral_id: 1,
+        loan_amount: outstanding_balance,
+        outstanding_balance,
+        total_repaid: 0,
+        interest_rate: 1200,
+        duration_months: 12,
+        monthly_payment: 0,
+        start_timestamp: 0,
+        next_payment_due: 0,
+        status: LoanStatus::Active,
+        payments_made: 0,
+        payments_missed: 0,
+        cumulative_borrow_rate: RATE_INDEX_SCALE,
+        last_accrual_timestamp: 0,
+        collateral_value,
+        liquidation_threshold_bps: 7500,
+        liquidation_bonus_bps: 500,
+        last_update: LastUpdate { timestamp: 0 },
+    }
+}
+
+#[test]
+fn request_loan_rejects_amounts_above_the_max_loan_to_value() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+    let h = setup(&env);
+    let client = LoanManagerClient::new(&env, &h.loan_manager);
+
+    MockOracleClient::new(&env, &h.oracle).set_nft_value(&1, &10_000);
+    let borrower = Address::generate(&env);
+
+    // Default loan_to_value_bps is 6000 (60%), so 6001 exceeds the cap.
+    let result = client.try_request_loan(&borrower, &1, &6001, &12);
+    assert!(result.is_err());
+}
+
+#[test]
+fn get_health_factor_reflects_interest_accrued_since_the_last_state_change() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+    let h = setup(&env);
+    let client = LoanManagerClient::new(&env, &h.loan_manager);
+
+    let borrower = Address::generate(&env);
+    let loan = active_loan(borrower, 8000, 10_000);
+    env.as_contract(&h.loan_manager, || {
+        env.storage().instance().set(&DataKey::Loan(1u64), &loan);
+        env.storage().instance().set(&DataKey::LoanCounter, &1u64);
+    });
+
+    let hf_at_issuance = client.get_health_factor(&1);
+
+    // Advance right up to the staleness limit without any other call touching
+    // the loan: the persisted outstanding_balance is stale, but the reported
+    // health factor should already reflect the interest that accrued since.
+    env.ledger().with_mut(|li| li.timestamp += MAX_STALENESS);
+    let hf_after_accrual = client.get_health_factor(&1);
+
+    assert!(hf_after_accrual < hf_at_issuance);
+}
+
+#[test]
+fn liquidate_is_capped_by_the_close_factor_and_stays_active() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+    let h = setup(&env);
+    let client = LoanManagerClient::new(&env, &h.loan_manager);
+
+    // Below 1.0 health factor: collateral_value * threshold_bps / outstanding_balance < 10000
+    let borrower = Address::generate(&env);
+    let loan = active_loan(borrower, 10_000, 10_000);
+    env.as_contract(&h.loan_manager, || {
+        env.storage().instance().set(&DataKey::Loan(1u64), &loan);
+        env.storage().instance().set(&DataKey::LoanCounter, &1u64);
+    });
+
+    let liquidator = Address::generate(&env);
+    mint_usdc(&h, &liquidator, 10_000);
+
+    // Close factor is 50%, so repaying the full balance in one call is rejected.
+    let result = client.try_liquidate(&1, &liquidator, &10_000);
+    assert!(result.is_err());
+
+    client.liquidate(&1, &liquidator, &5000);
+    let updated = client.get_loan(&1);
+    assert_eq!(updated.outstanding_balance, 5000);
+    assert!(updated.status == LoanStatus::Active);
+}
+
+// ---------------------------------------------------------------------------
+// Multi-token oracle-converted repayment (chunk0-5)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn make_payment_converts_a_whitelisted_alt_token_via_the_oracle() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+    let h = setup(&env);
+    let client = LoanManagerClient::new(&env, &h.loan_manager);
+
+    MockOracleClient::new(&env, &h.oracle).set_nft_value(&1, &10_000);
+
+    let borrower = Address::generate(&env);
+    let loan_id = client.request_loan(&borrower, &1, &6000, &12);
+    client.approve_loan(&loan_id);
+
+    let alt_admin = Address::generate(&env);
+    let alt_token = env.register_stellar_asset_contract_v2(alt_admin).address();
+
+    // 1 alt token == 0.5 USDC
+    MockOracleClient::new(&env, &h.oracle).set_token_price(&alt_token, &(PRICE_SCALE / 2));
+    client.set_accepted_tokens(&Vec::from_array(&env, [alt_token.clone()]));
+
+    token::StellarAssetClient::new(&env, &alt_token).mint(&borrower, &2000);
+
+    let balance_before = client.get_loan(&loan_id).outstanding_balance;
+    client.make_payment(&loan_id, &2000, &alt_token);
+    let loan = client.get_loan(&loan_id);
+
+    // 2000 alt tokens at 0.5 USDC each credits 1000 USDC-equivalent.
+    assert_eq!(loan.outstanding_balance, balance_before - 1000);
+    assert_eq!(loan.total_repaid, 1000);
+
+    let alt_client = token::Client::new(&env, &alt_token);
+    assert_eq!(alt_client.balance(&borrower), 0);
+}
+
+#[test]
+fn make_payment_rejects_a_token_outside_the_whitelist() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+    let h = setup(&env);
+    let client = LoanManagerClient::new(&env, &h.loan_manager);
+
+    MockOracleClient::new(&env, &h.oracle).set_nft_value(&1, &10_000);
+
+    let borrower = Address::generate(&env);
+    let loan_id = client.request_loan(&borrower, &1, &6000, &12);
+    client.approve_loan(&loan_id);
+
+    let untrusted_admin = Address::generate(&env);
+    let untrusted_token = env.register_stellar_asset_contract_v2(untrusted_admin).address();
+    token::StellarAssetClient::new(&env, &untrusted_token).mint(&borrower, &2000);
+
+    let result = client.try_make_payment(&loan_id, &2000, &untrusted_token);
+    assert!(result.is_err());
+}
+
+// ---------------------------------------------------------------------------
+// Stale-valuation guard (chunk0-6)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn approve_loan_rejects_once_the_valuation_is_stale() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+    let h = setup(&env);
+    let client = LoanManagerClient::new(&env, &h.loan_manager);
+
+    MockOracleClient::new(&env, &h.oracle).set_nft_value(&1, &10_000);
+
+    let borrower = Address::generate(&env);
+    let loan_id = client.request_loan(&borrower, &1, &6000, &12);
+
+    env.ledger().with_mut(|li| li.timestamp += MAX_STALENESS + 1);
+    let result = client.try_approve_loan(&loan_id);
+    assert!(result.is_err());
+}
+
+#[test]
+fn get_health_factor_and_liquidate_reject_stale_valuations() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 0);
+    let h = setup(&env);
+    let client = LoanManagerClient::new(&env, &h.loan_manager);
+
+    let borrower = Address::generate(&env);
+    let loan = active_loan(borrower, 10_000, 10_000);
+    env.as_contract(&h.loan_manager, || {
+        env.storage().instance().set(&DataKey::Loan(1u64), &loan);
+        env.storage().instance().set(&DataKey::LoanCounter, &1u64);
+    });
+
+    env.ledger().with_mut(|li| li.timestamp += MAX_STALENESS + 1);
+
+    let liquidator = Address::generate(&env);
+    mint_usdc(&h, &liquidator, 10_000);
+
+    assert!(client.try_get_health_factor(&1).is_err());
+    assert!(client.try_liquidate(&1, &liquidator, &5000).is_err());
+}